@@ -0,0 +1,228 @@
+use crate::manifest::StreamingHasher;
+use crate::DownloadProgress;
+use futures_util::StreamExt;
+use reqwest::StatusCode;
+use std::path::Path;
+use std::time::Instant;
+use tauri::{Emitter, Window};
+use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+
+/// Total number of request attempts (the first try included) made before the
+/// download is reported as failed.
+const MAX_ATTEMPTS: u32 = 5;
+
+/// Downloads `url` into `file_path`, resuming from any partial file already
+/// present and retrying transient network errors with exponential backoff.
+/// Returns the total bytes written and the hex-encoded SHA-256 digest of the
+/// complete file.
+pub async fn download_with_resume(
+    window: &Window,
+    url: &str,
+    file_path: &Path,
+    expected_size: Option<u64>,
+) -> Result<(u64, String), String> {
+    let mut downloaded = existing_partial_size(file_path).await;
+    if let Some(expected) = expected_size {
+        if downloaded > expected {
+            // A stale leftover from a previous, different download is larger
+            // than what we're about to fetch; a Range request built from it
+            // would be unsatisfiable, so start clean instead.
+            downloaded = 0;
+        }
+    }
+
+    let mut hasher = StreamingHasher::new();
+    if downloaded > 0 {
+        rehash_existing(file_path, &mut hasher).await?;
+    }
+
+    let mut attempt = 0;
+    let start_time = Instant::now();
+    let mut last_update_time = start_time;
+    let mut total_size: Option<u64> = None;
+
+    loop {
+        attempt += 1;
+
+        let client = reqwest::Client::new();
+        let mut request = client.get(url);
+        if downloaded > 0 {
+            request = request.header("Range", format!("bytes={}-", downloaded));
+        }
+
+        let response = match request.send().await {
+            Ok(response) => response,
+            Err(e) => {
+                if attempt >= MAX_ATTEMPTS {
+                    return Err(format!("Failed to start download: {}", e));
+                }
+                backoff(window, attempt).await;
+                continue;
+            }
+        };
+
+        if response.status() == StatusCode::RANGE_NOT_SATISFIABLE {
+            // The partial file on disk is stale or oversized relative to
+            // what the server has; a Range request built from it can never
+            // succeed, so drop it and restart from scratch instead of
+            // retrying the same doomed request.
+            downloaded = 0;
+            hasher = StreamingHasher::new();
+            if attempt >= MAX_ATTEMPTS {
+                return Err("Server rejected resume range even after restarting from scratch".to_string());
+            }
+            continue;
+        }
+
+        let resumed = match response.status() {
+            StatusCode::PARTIAL_CONTENT => true,
+            StatusCode::OK => {
+                // Server doesn't support ranges (or the file changed); restart clean.
+                downloaded = 0;
+                hasher = StreamingHasher::new();
+                false
+            }
+            status => {
+                if attempt >= MAX_ATTEMPTS {
+                    return Err(format!("Unexpected response status: {}", status));
+                }
+                backoff(window, attempt).await;
+                continue;
+            }
+        };
+
+        total_size = response
+            .content_length()
+            .map(|len| if resumed { len + downloaded } else { len })
+            .or(total_size);
+
+        let mut file = if resumed {
+            let mut file = tokio::fs::OpenOptions::new()
+                .append(true)
+                .open(file_path)
+                .await
+                .map_err(|e| format!("Failed to open file for resume: {}", e))?;
+            file.seek(std::io::SeekFrom::End(0))
+                .await
+                .map_err(|e| format!("Failed to seek file for resume: {}", e))?;
+            file
+        } else {
+            tokio::fs::File::create(file_path)
+                .await
+                .map_err(|e| format!("Failed to create file: {}", e))?
+        };
+
+        let mut stream = response.bytes_stream();
+        let mut stream_failed = false;
+
+        while let Some(item) = stream.next().await {
+            let chunk = match item {
+                Ok(chunk) => chunk,
+                Err(e) => {
+                    eprintln!("Download stream interrupted: {}", e);
+                    stream_failed = true;
+                    break;
+                }
+            };
+
+            if let Err(e) = file.write_all(&chunk).await {
+                return Err(format!("Write error: {}", e));
+            }
+
+            downloaded += chunk.len() as u64;
+            hasher.update(&chunk);
+
+            let current_time = Instant::now();
+            let elapsed = current_time.duration_since(last_update_time).as_secs_f64();
+
+            if elapsed >= 0.1 {
+                emit_progress(window, downloaded, total_size, start_time, current_time);
+                last_update_time = current_time;
+            }
+        }
+
+        drop(file);
+
+        if !stream_failed {
+            break;
+        }
+
+        if attempt >= MAX_ATTEMPTS {
+            return Err(format!(
+                "Download failed after {} attempts: connection kept dropping",
+                MAX_ATTEMPTS
+            ));
+        }
+
+        backoff(window, attempt).await;
+    }
+
+    Ok((downloaded, hasher.finalize_hex()))
+}
+
+async fn existing_partial_size(file_path: &Path) -> u64 {
+    tokio::fs::metadata(file_path)
+        .await
+        .map(|metadata| metadata.len())
+        .unwrap_or(0)
+}
+
+/// Re-hashes the bytes already on disk so the final digest covers the whole
+/// file, not just the bytes downloaded in this attempt.
+async fn rehash_existing(file_path: &Path, hasher: &mut StreamingHasher) -> Result<(), String> {
+    let contents = tokio::fs::read(file_path)
+        .await
+        .map_err(|e| format!("Failed to read partial file: {}", e))?;
+    hasher.update(&contents);
+    Ok(())
+}
+
+async fn backoff(window: &Window, attempt: u32) {
+    let _ = window.emit(
+        "download-retry",
+        &serde_json::json!({ "attempt": attempt, "maxAttempts": MAX_ATTEMPTS }),
+    );
+    let delay_secs = 2u64.saturating_pow(attempt.min(5));
+    tokio::time::sleep(tokio::time::Duration::from_secs(delay_secs)).await;
+}
+
+fn emit_progress(
+    window: &Window,
+    downloaded: u64,
+    total_size: Option<u64>,
+    start_time: Instant,
+    current_time: Instant,
+) {
+    let total_elapsed = current_time.duration_since(start_time).as_secs_f64();
+    let speed = if total_elapsed > 0.0 {
+        downloaded as f64 / total_elapsed
+    } else {
+        0.0
+    };
+
+    let percentage = if let Some(total) = total_size {
+        (downloaded as f64 / total as f64) * 100.0
+    } else {
+        -1.0
+    };
+
+    let eta = if let Some(total) = total_size {
+        if speed > 0.0 && downloaded < total {
+            Some((total - downloaded) as f64 / speed)
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+
+    let progress = DownloadProgress {
+        downloaded,
+        total: total_size,
+        percentage,
+        speed,
+        eta,
+    };
+
+    let _ = window.emit("download-progress", &progress);
+}
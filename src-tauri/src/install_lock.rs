@@ -0,0 +1,80 @@
+/// Error string returned when another installer instance already holds the
+/// install lock. The frontend matches on this exact message to show a
+/// dedicated "already running" prompt instead of a generic failure.
+pub const ALREADY_RUNNING_ERROR: &str = "InstallAlreadyRunning";
+
+/// Held for the duration of a destructive install step (wiping the previous
+/// installation, running the silent setup) so two installer instances can't
+/// race on the same directory and registry keys. Released when dropped.
+pub struct InstallLock {
+    #[cfg(target_os = "windows")]
+    handle: windows::InstallMutexHandle,
+}
+
+impl InstallLock {
+    /// Acquires the global install lock, returning
+    /// `Err(ALREADY_RUNNING_ERROR)` if another instance already holds it.
+    /// Only Windows has a lock to take; on other platforms this always
+    /// succeeds.
+    pub fn acquire() -> Result<Self, String> {
+        #[cfg(target_os = "windows")]
+        {
+            Ok(Self {
+                handle: windows::acquire_mutex()?,
+            })
+        }
+
+        #[cfg(not(target_os = "windows"))]
+        {
+            Ok(Self {})
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod windows {
+    use super::ALREADY_RUNNING_ERROR;
+    use std::ffi::OsStr;
+    use std::os::windows::ffi::OsStrExt;
+    use windows_sys::Win32::Foundation::{CloseHandle, ERROR_ALREADY_EXISTS, HANDLE};
+    use windows_sys::Win32::System::Threading::{CreateMutexW, ReleaseMutex};
+
+    /// Must be prefixed with `Global\` so the lock is shared across all
+    /// user sessions, not just the one that created it.
+    const MUTEX_NAME: &str = "Global\\HydraInstallerMutex";
+
+    pub struct InstallMutexHandle(HANDLE);
+
+    pub fn acquire_mutex() -> Result<InstallMutexHandle, String> {
+        let wide_name: Vec<u16> = OsStr::new(MUTEX_NAME)
+            .encode_wide()
+            .chain(std::iter::once(0))
+            .collect();
+
+        unsafe {
+            let handle = CreateMutexW(std::ptr::null(), 1, wide_name.as_ptr());
+            if handle == 0 {
+                return Err(format!(
+                    "Failed to create install mutex: {}",
+                    std::io::Error::last_os_error()
+                ));
+            }
+
+            if windows_sys::Win32::Foundation::GetLastError() == ERROR_ALREADY_EXISTS {
+                CloseHandle(handle);
+                return Err(ALREADY_RUNNING_ERROR.to_string());
+            }
+
+            Ok(InstallMutexHandle(handle))
+        }
+    }
+
+    impl Drop for InstallMutexHandle {
+        fn drop(&mut self) {
+            unsafe {
+                ReleaseMutex(self.0);
+                CloseHandle(self.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Public key used to verify release manifests, generated and held offline by
+/// the Hydra release team. Replace with the real publisher key before
+/// shipping a build.
+const PUBLISHER_PUBLIC_KEY: [u8; 32] = [0u8; 32];
+
+// A forgotten key swap must not silently ship: fail release builds outright
+// if the placeholder all-zero key is still in place. Debug builds are left
+// alone so local development doesn't need a real key.
+#[cfg(not(debug_assertions))]
+const _: () = assert!(
+    !is_placeholder_key(&PUBLISHER_PUBLIC_KEY),
+    "PUBLISHER_PUBLIC_KEY is still the placeholder all-zero key; set the real publisher key before a release build"
+);
+
+const fn is_placeholder_key(key: &[u8; 32]) -> bool {
+    let mut i = 0;
+    while i < key.len() {
+        if key[i] != 0 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Platform/architecture identifier a manifest's `target` field must match,
+/// e.g. `"windows-x86_64"`. Computed the same way on every build so a
+/// manifest meant for another platform is rejected before it's downloaded.
+pub fn current_target() -> String {
+    format!("{}-{}", std::env::consts::OS, std::env::consts::ARCH)
+}
+
+/// A signed description of the installer that should be downloaded for a
+/// given platform/target, fetched from the update endpoint before any bytes
+/// are pulled.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UpdateManifest {
+    pub version: String,
+    pub target: String,
+    pub url: String,
+    pub size: u64,
+    pub sha256: String,
+    /// Hex-encoded detached ed25519 signature over the canonical manifest
+    /// fields (see `UpdateManifest::signed_payload`).
+    pub signature: String,
+}
+
+impl UpdateManifest {
+    /// Canonical byte representation that the signature covers. Every
+    /// variable-length field is prefixed with its length as a 4-byte
+    /// big-endian integer so field boundaries are unambiguous — a delimiter
+    /// like `\n` inside a field value can't be mistaken for the start of
+    /// the next field and still validate against the same signature.
+    fn signed_payload(&self) -> Vec<u8> {
+        let mut payload = Vec::new();
+        Self::append_field(&mut payload, self.version.as_bytes());
+        Self::append_field(&mut payload, self.target.as_bytes());
+        Self::append_field(&mut payload, self.url.as_bytes());
+        Self::append_field(&mut payload, &self.size.to_be_bytes());
+        Self::append_field(&mut payload, self.sha256.as_bytes());
+        payload
+    }
+
+    fn append_field(payload: &mut Vec<u8>, field: &[u8]) {
+        payload.extend_from_slice(&(field.len() as u32).to_be_bytes());
+        payload.extend_from_slice(field);
+    }
+
+    /// Verifies the detached signature against the embedded publisher key.
+    pub fn verify_signature(&self) -> Result<(), String> {
+        let verifying_key = VerifyingKey::from_bytes(&PUBLISHER_PUBLIC_KEY)
+            .map_err(|e| format!("Invalid embedded public key: {}", e))?;
+
+        let signature_bytes = hex::decode(&self.signature)
+            .map_err(|e| format!("Malformed manifest signature: {}", e))?;
+        let signature = Signature::from_slice(&signature_bytes)
+            .map_err(|e| format!("Malformed manifest signature: {}", e))?;
+
+        verifying_key
+            .verify(&self.signed_payload(), &signature)
+            .map_err(|_| "Manifest signature verification failed".to_string())
+    }
+}
+
+/// Fetches and verifies the update manifest from `manifest_url`. Returns the
+/// manifest only if its signature checks out against the embedded publisher
+/// key.
+pub async fn fetch_and_verify_manifest(manifest_url: &str) -> Result<UpdateManifest, String> {
+    let manifest: UpdateManifest = reqwest::get(manifest_url)
+        .await
+        .map_err(|e| format!("Failed to fetch update manifest: {}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse update manifest: {}", e))?;
+
+    manifest.verify_signature()?;
+
+    Ok(manifest)
+}
+
+/// Streaming SHA-256 hasher used to verify a downloaded installer without
+/// re-reading the file from disk afterwards.
+pub struct StreamingHasher {
+    hasher: Sha256,
+}
+
+impl StreamingHasher {
+    pub fn new() -> Self {
+        Self {
+            hasher: Sha256::new(),
+        }
+    }
+
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.hasher.update(chunk);
+    }
+
+    pub fn finalize_hex(self) -> String {
+        hex::encode(self.hasher.finalize())
+    }
+}
+
+/// Checks a completed download against the manifest's expected size and
+/// digest.
+pub fn verify_download(manifest: &UpdateManifest, downloaded: u64, digest_hex: &str) -> Result<(), String> {
+    if downloaded != manifest.size {
+        return Err(format!(
+            "Downloaded size {} does not match expected size {}",
+            downloaded, manifest.size
+        ));
+    }
+
+    if !digest_hex.eq_ignore_ascii_case(&manifest.sha256) {
+        return Err("Downloaded file digest does not match update manifest".to_string());
+    }
+
+    Ok(())
+}
+
+/// Rejects a manifest that wasn't built for the platform this binary is
+/// running on, even though its signature and digest are otherwise valid.
+pub fn verify_target(manifest: &UpdateManifest) -> Result<(), String> {
+    let expected = current_target();
+    if manifest.target != expected {
+        return Err(format!(
+            "Update manifest targets '{}' but this build is '{}'",
+            manifest.target, expected
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_manifest() -> UpdateManifest {
+        UpdateManifest {
+            version: "1.2.3".to_string(),
+            target: "windows-x86_64".to_string(),
+            url: "https://example.com/Hydra-Setup.exe".to_string(),
+            size: 1024,
+            sha256: "abc123".to_string(),
+            signature: "deadbeef".to_string(),
+        }
+    }
+
+    #[test]
+    fn signed_payload_is_deterministic() {
+        let manifest = sample_manifest();
+        assert_eq!(manifest.signed_payload(), manifest.signed_payload());
+    }
+
+    #[test]
+    fn signed_payload_changes_with_url() {
+        let mut other = sample_manifest();
+        other.url = "https://example.com/tampered.exe".to_string();
+        assert_ne!(sample_manifest().signed_payload(), other.signed_payload());
+    }
+
+    #[test]
+    fn verify_download_accepts_matching_size_and_digest() {
+        let manifest = sample_manifest();
+        assert!(verify_download(&manifest, 1024, "ABC123").is_ok());
+    }
+
+    #[test]
+    fn verify_download_rejects_size_mismatch() {
+        let manifest = sample_manifest();
+        assert!(verify_download(&manifest, 999, "abc123").is_err());
+    }
+
+    #[test]
+    fn verify_download_rejects_digest_mismatch() {
+        let manifest = sample_manifest();
+        assert!(verify_download(&manifest, 1024, "wrongdigest").is_err());
+    }
+
+    #[test]
+    fn verify_target_rejects_mismatched_platform() {
+        let mut manifest = sample_manifest();
+        manifest.target = "not-a-real-target".to_string();
+        assert!(verify_target(&manifest).is_err());
+    }
+}
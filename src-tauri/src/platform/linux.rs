@@ -0,0 +1,131 @@
+use super::PlatformInstaller;
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+
+pub struct LinuxInstaller;
+
+#[async_trait]
+impl PlatformInstaller for LinuxInstaller {
+    async fn installation_path(&self) -> Result<Option<String>, String> {
+        let appimage_path = self.data_dir()?.join("Hydra.AppImage");
+        if appimage_path.exists() {
+            return Ok(Some(appimage_path.to_string_lossy().to_string()));
+        }
+
+        // .deb/.rpm installs drop Hydra in the usual XDG binary locations.
+        for candidate in ["/usr/bin/hydra", "/usr/local/bin/hydra", "/opt/hydralauncher/hydra"] {
+            if Path::new(candidate).exists() {
+                return Ok(Some(candidate.to_string()));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn installed_version(&self) -> Result<Option<String>, String> {
+        // Neither AppImages nor .deb/.rpm packages expose a queryable
+        // installed version through a single OS-wide mechanism, so version
+        // comparison is skipped on Linux for now.
+        Ok(None)
+    }
+
+    async fn kill_process(&self) -> Result<(), String> {
+        super::pkill("Hydra").await
+    }
+
+    async fn launch(&self, path: &str) -> Result<(), String> {
+        use tokio::process::Command;
+
+        let hydra_path = Path::new(path);
+        if !hydra_path.exists() {
+            return Err(format!(
+                "Hydra executable not found at: {}",
+                hydra_path.display()
+            ));
+        }
+
+        Command::new(hydra_path)
+            .spawn()
+            .map_err(|e| format!("Failed to launch Hydra: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn install(&self, installer_path: &Path) -> Result<(), String> {
+        use tokio::process::Command;
+
+        let extension = installer_path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+
+        match extension.as_str() {
+            "appimage" => {
+                let data_dir = self.data_dir()?;
+                tokio::fs::create_dir_all(&data_dir)
+                    .await
+                    .map_err(|e| format!("Failed to create data directory: {}", e))?;
+
+                let target = data_dir.join("Hydra.AppImage");
+                tokio::fs::copy(installer_path, &target)
+                    .await
+                    .map_err(|e| format!("Failed to install AppImage: {}", e))?;
+
+                use std::os::unix::fs::PermissionsExt;
+                let mut permissions = tokio::fs::metadata(&target)
+                    .await
+                    .map_err(|e| format!("Failed to read AppImage permissions: {}", e))?
+                    .permissions();
+                permissions.set_mode(0o755);
+                tokio::fs::set_permissions(&target, permissions)
+                    .await
+                    .map_err(|e| format!("Failed to make AppImage executable: {}", e))?;
+
+                Ok(())
+            }
+            // dpkg/rpm need root; pkexec prompts the desktop session for
+            // elevation instead of failing with a permission error.
+            "deb" => {
+                run_silent_install(Command::new("pkexec").args(&[
+                    "dpkg",
+                    "-i",
+                    &installer_path.to_string_lossy(),
+                ]))
+                .await
+            }
+            "rpm" => {
+                run_silent_install(Command::new("pkexec").args(&[
+                    "rpm",
+                    "-i",
+                    &installer_path.to_string_lossy(),
+                ]))
+                .await
+            }
+            other => Err(format!("Unsupported Linux installer format: .{}", other)),
+        }
+    }
+
+    fn data_dir(&self) -> Result<PathBuf, String> {
+        let mut path = dirs::data_dir().ok_or("Failed to get XDG data directory")?;
+        path.push("hydralauncher");
+        Ok(path)
+    }
+}
+
+async fn run_silent_install(cmd: &mut tokio::process::Command) -> Result<(), String> {
+    let output = cmd
+        .output()
+        .await
+        .map_err(|e| format!("Failed to run installer: {}", e))?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Installer exited with code {:?}: {}",
+            output.status.code(),
+            String::from_utf8_lossy(&output.stderr)
+        ))
+    }
+}
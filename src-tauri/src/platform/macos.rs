@@ -0,0 +1,180 @@
+use super::PlatformInstaller;
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+
+pub struct MacOsInstaller;
+
+const APP_BUNDLE_PATH: &str = "/Applications/Hydra.app";
+
+#[async_trait]
+impl PlatformInstaller for MacOsInstaller {
+    async fn installation_path(&self) -> Result<Option<String>, String> {
+        if Path::new(APP_BUNDLE_PATH).exists() {
+            return Ok(Some(APP_BUNDLE_PATH.to_string()));
+        }
+
+        Ok(None)
+    }
+
+    async fn installed_version(&self) -> Result<Option<String>, String> {
+        let plist_path = Path::new(APP_BUNDLE_PATH).join("Contents/Info.plist");
+        if !plist_path.exists() {
+            return Ok(None);
+        }
+
+        let output = tokio::process::Command::new("defaults")
+            .args(&[
+                "read",
+                &plist_path.with_extension("").to_string_lossy().to_string(),
+                "CFBundleShortVersionString",
+            ])
+            .output()
+            .await
+            .map_err(|e| format!("Failed to read app bundle version: {}", e))?;
+
+        if !output.status.success() {
+            return Ok(None);
+        }
+
+        Ok(Some(String::from_utf8_lossy(&output.stdout).trim().to_string()))
+    }
+
+    async fn kill_process(&self) -> Result<(), String> {
+        super::pkill("Hydra").await
+    }
+
+    async fn launch(&self, path: &str) -> Result<(), String> {
+        use tokio::process::Command;
+
+        if !Path::new(path).exists() {
+            return Err(format!("Hydra application not found at: {}", path));
+        }
+
+        Command::new("open")
+            .arg(path)
+            .spawn()
+            .map_err(|e| format!("Failed to launch Hydra: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn install(&self, installer_path: &Path) -> Result<(), String> {
+        use tokio::process::Command;
+
+        let extension = installer_path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+
+        match extension.as_str() {
+            // `installer -target /` needs root; routing it through
+            // `osascript ... with administrator privileges` prompts for the
+            // admin password instead of failing outright.
+            "pkg" => {
+                let shell_command = format!(
+                    "installer -pkg {} -target /",
+                    shell_quote(&installer_path.to_string_lossy())
+                );
+                let script = format!(
+                    "do shell script \"{}\" with administrator privileges",
+                    escape_applescript_string(&shell_command)
+                );
+
+                let output = Command::new("osascript")
+                    .args(&["-e", &script])
+                    .output()
+                    .await
+                    .map_err(|e| format!("Failed to run installer: {}", e))?;
+
+                if output.status.success() {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "Installer exited with code {:?}: {}",
+                        output.status.code(),
+                        String::from_utf8_lossy(&output.stderr)
+                    ))
+                }
+            }
+            "dmg" => install_from_dmg(installer_path).await,
+            other => Err(format!("Unsupported macOS installer format: .{}", other)),
+        }
+    }
+
+    fn data_dir(&self) -> Result<PathBuf, String> {
+        let mut path = dirs::home_dir().ok_or("Failed to get home directory")?;
+        path.push("Library");
+        path.push("Application Support");
+        path.push("hydralauncher");
+        Ok(path)
+    }
+}
+
+/// Wraps `value` in single quotes for safe use as one argument in a `/bin/sh`
+/// command string, escaping any single quotes it contains.
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// Escapes a string for embedding inside an AppleScript double-quoted
+/// string literal passed to `osascript -e`.
+fn escape_applescript_string(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Mounts the disk image, copies the app bundle to `/Applications`, and
+/// unmounts it again.
+async fn install_from_dmg(dmg_path: &Path) -> Result<(), String> {
+    use tokio::process::Command;
+
+    let mount_point = std::env::temp_dir().join("hydra-dmg-mount");
+    tokio::fs::create_dir_all(&mount_point)
+        .await
+        .map_err(|e| format!("Failed to create mount point: {}", e))?;
+
+    let attach_status = Command::new("hdiutil")
+        .args(&[
+            "attach",
+            &dmg_path.to_string_lossy(),
+            "-mountpoint",
+            &mount_point.to_string_lossy(),
+            "-nobrowse",
+            "-quiet",
+        ])
+        .status()
+        .await
+        .map_err(|e| format!("Failed to mount disk image: {}", e))?;
+
+    if !attach_status.success() {
+        return Err("Failed to mount disk image".to_string());
+    }
+
+    // The destination may already hold a previous version of the bundle;
+    // `ditto` merges into an existing directory just like `cp -R` would, so
+    // it has to be removed first or files dropped between versions survive
+    // the "update".
+    let _ = tokio::fs::remove_dir_all(APP_BUNDLE_PATH).await;
+
+    let copy_result = Command::new("ditto")
+        .args(&[
+            "--noextattr",
+            "--noqtn",
+            &mount_point.join("Hydra.app").to_string_lossy(),
+            APP_BUNDLE_PATH,
+        ])
+        .status()
+        .await
+        .map_err(|e| format!("Failed to copy app bundle: {}", e));
+
+    let _ = Command::new("hdiutil")
+        .args(&["detach", &mount_point.to_string_lossy(), "-quiet"])
+        .status()
+        .await;
+
+    match copy_result {
+        Ok(status) if status.success() => Ok(()),
+        Ok(status) => Err(format!("Failed to copy app bundle, exit code: {:?}", status.code())),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(target_os = "linux")]
+mod linux;
+#[cfg(target_os = "macos")]
+mod macos;
+#[cfg(target_os = "windows")]
+mod windows;
+
+use async_trait::async_trait;
+use std::path::Path;
+
+/// Platform-specific knowledge needed to locate, install, launch and kill
+/// the Hydra client. Each desktop OS has its own conventions for install
+/// locations and process management, so every meaningful installer command
+/// dispatches through here instead of branching on `cfg(target_os)` inline.
+#[async_trait]
+pub trait PlatformInstaller: Send + Sync {
+    /// Locates the currently installed Hydra, if any: its executable on
+    /// Linux, its `.app` bundle on macOS, and on Windows either the
+    /// executable or, from some registry values, the install directory.
+    async fn installation_path(&self) -> Result<Option<String>, String>;
+
+    /// Reads the version of the currently installed Hydra, if any, so the
+    /// frontend can decide whether an update is actually needed.
+    async fn installed_version(&self) -> Result<Option<String>, String>;
+
+    /// Terminates any running Hydra process.
+    async fn kill_process(&self) -> Result<(), String>;
+
+    /// Launches the Hydra executable at `path`.
+    async fn launch(&self, path: &str) -> Result<(), String>;
+
+    /// Silently runs the downloaded installer at `installer_path`.
+    async fn install(&self, installer_path: &Path) -> Result<(), String>;
+
+    /// The directory Hydra's user data lives in, used to wipe a previous
+    /// installation before reinstalling.
+    fn data_dir(&self) -> Result<std::path::PathBuf, String>;
+}
+
+/// Kills any process matching `pattern` via `pkill -f`, used by the Linux
+/// and macOS installers which both manage the Hydra process the same way.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+pub(crate) async fn pkill(pattern: &str) -> Result<(), String> {
+    use tokio::process::Command;
+
+    let output = Command::new("pkill").args(&["-f", pattern]).output().await;
+
+    match output {
+        // pkill exits 1 when no process matched, which isn't an error for us.
+        Ok(result) => match result.status.code() {
+            Some(0) | Some(1) => Ok(()),
+            _ => Err(format!(
+                "Failed to kill Hydra process: {}",
+                String::from_utf8_lossy(&result.stderr)
+            )),
+        },
+        Err(e) => Err(format!("Failed to execute pkill: {}", e)),
+    }
+}
+
+/// Returns the installer implementation for the OS this binary was built
+/// for.
+pub fn current() -> Box<dyn PlatformInstaller> {
+    #[cfg(target_os = "windows")]
+    {
+        Box::new(windows::WindowsInstaller)
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        Box::new(linux::LinuxInstaller)
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        Box::new(macos::MacOsInstaller)
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "linux", target_os = "macos")))]
+    {
+        compile_error!("Hydra installer does not support this target OS");
+    }
+}
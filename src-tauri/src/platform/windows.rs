@@ -0,0 +1,185 @@
+use super::PlatformInstaller;
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+
+pub struct WindowsInstaller;
+
+/// Finds the `Uninstall` registry subkey Hydra's installer registered
+/// itself under, searching both the 64-bit and 32-bit views as well as the
+/// per-user hive.
+fn find_uninstall_subkey() -> Option<winreg::RegKey> {
+    use winreg::enums::*;
+    use winreg::RegKey;
+
+    let registry_paths = vec![
+        (
+            "SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Uninstall",
+            HKEY_LOCAL_MACHINE,
+        ),
+        (
+            "SOFTWARE\\WOW6432Node\\Microsoft\\Windows\\CurrentVersion\\Uninstall",
+            HKEY_LOCAL_MACHINE,
+        ),
+        (
+            "SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Uninstall",
+            HKEY_CURRENT_USER,
+        ),
+    ];
+
+    for (path, hkey) in registry_paths {
+        let hkcu = RegKey::predef(hkey);
+        let uninstall_key = match hkcu.open_subkey(path) {
+            Ok(key) => key,
+            Err(_) => continue,
+        };
+
+        for key_name in uninstall_key.enum_keys().map(|x| x.unwrap()) {
+            let subkey = match uninstall_key.open_subkey(&key_name) {
+                Ok(key) => key,
+                Err(_) => continue,
+            };
+
+            let display_name: String = match subkey.get_value("DisplayName") {
+                Ok(name) => name,
+                Err(_) => continue,
+            };
+
+            let publisher: String = match subkey.get_value("Publisher") {
+                Ok(pub_name) => pub_name,
+                Err(_) => continue,
+            };
+
+            if display_name == "Hydra" && publisher == "Los Broxas" {
+                return Some(subkey);
+            }
+        }
+    }
+
+    None
+}
+
+#[async_trait]
+impl PlatformInstaller for WindowsInstaller {
+    async fn installation_path(&self) -> Result<Option<String>, String> {
+        let Some(subkey) = find_uninstall_subkey() else {
+            return Ok(None);
+        };
+
+        // Try InstallLocation first
+        if let Ok(install_location) = subkey.get_value::<String, _>("InstallLocation") {
+            if !install_location.is_empty() {
+                return Ok(Some(install_location));
+            }
+        }
+
+        // Fallback to UninstallString and extract directory
+        if let Ok(uninstall_string) = subkey.get_value::<String, _>("UninstallString") {
+            // UninstallString often has quotes and arguments like: "C:\Path\Uninstall.exe" /S
+            // Extract just the path part
+            let uninstall_path = uninstall_string
+                .trim()
+                .trim_matches('"')
+                .split_whitespace()
+                .next()
+                .unwrap_or(&uninstall_string);
+
+            if let Some(parent) = std::path::Path::new(uninstall_path).parent() {
+                let hydra_exe = parent.join("Hydra.exe");
+                return Ok(Some(hydra_exe.to_string_lossy().to_string()));
+            }
+        }
+
+        // Fallback to DisplayIcon and extract directory
+        if let Ok(display_icon) = subkey.get_value::<String, _>("DisplayIcon") {
+            // DisplayIcon might have an index like "C:\Path\file.exe,0"
+            let icon_path = display_icon.split(',').next().unwrap_or(&display_icon);
+            if let Some(parent) = std::path::Path::new(icon_path).parent() {
+                return Ok(Some(parent.to_string_lossy().to_string()));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn installed_version(&self) -> Result<Option<String>, String> {
+        let Some(subkey) = find_uninstall_subkey() else {
+            return Ok(None);
+        };
+
+        Ok(subkey.get_value::<String, _>("DisplayVersion").ok())
+    }
+
+    async fn kill_process(&self) -> Result<(), String> {
+        use tokio::process::Command;
+
+        let output = Command::new("taskkill")
+            .args(&["/F", "/IM", "Hydra.exe", "/T"])
+            .output()
+            .await;
+
+        match output {
+            Ok(result) => {
+                if result.status.success() || result.status.code() == Some(128) {
+                    Ok(())
+                } else {
+                    let stderr = String::from_utf8_lossy(&result.stderr);
+                    if stderr.contains("not found") || stderr.contains("not running") {
+                        Ok(())
+                    } else {
+                        Err(format!("Failed to kill Hydra process: {}", stderr))
+                    }
+                }
+            }
+            Err(e) => Err(format!("Failed to execute taskkill: {}", e)),
+        }
+    }
+
+    async fn launch(&self, path: &str) -> Result<(), String> {
+        use tokio::process::Command;
+
+        let hydra_path = Path::new(path);
+
+        if !hydra_path.exists() {
+            return Err(format!(
+                "Hydra executable not found at: {}",
+                hydra_path.display()
+            ));
+        }
+
+        Command::new(hydra_path)
+            .spawn()
+            .map_err(|e| format!("Failed to launch Hydra: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn install(&self, installer_path: &Path) -> Result<(), String> {
+        use tokio::process::Command;
+
+        let mut cmd = Command::new(installer_path);
+        cmd.args(&["/S", "/NORESTART"]);
+
+        let mut child = cmd
+            .spawn()
+            .map_err(|e| format!("Failed to start installer: {}", e))?;
+
+        let status = child
+            .wait()
+            .await
+            .map_err(|e| format!("Failed to wait for installer: {}", e))?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(format!("Installer exited with code: {:?}", status.code()))
+        }
+    }
+
+    fn data_dir(&self) -> Result<PathBuf, String> {
+        let mut path = dirs::home_dir().ok_or("Failed to get home directory")?;
+        path.push("AppData");
+        path.push("Roaming");
+        path.push("hydralauncher");
+        Ok(path)
+    }
+}
@@ -0,0 +1,138 @@
+use tauri::{Emitter, Window};
+
+/// Checks for and installs the Edge WebView2 runtime, which Tauri requires
+/// to render anything on Windows. Without it the installed app opens to a
+/// blank window instead of a clear error, so this runs before the installer
+/// itself.
+pub async fn ensure_present(window: &Window) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        if windows::is_installed() {
+            return Ok(());
+        }
+
+        let _ = window.emit(
+            "prerequisite-progress",
+            &serde_json::json!({ "name": "WebView2", "status": "installing" }),
+        );
+
+        if let Err(e) = windows::install_evergreen_bootstrapper().await {
+            let _ = window.emit(
+                "prerequisite-failed",
+                &serde_json::json!({ "name": "WebView2", "error": e }),
+            );
+            return Err(e);
+        }
+
+        if !windows::is_installed() {
+            let error = "WebView2 bootstrapper completed but the runtime is still missing".to_string();
+            let _ = window.emit(
+                "prerequisite-failed",
+                &serde_json::json!({ "name": "WebView2", "error": error }),
+            );
+            return Err(error);
+        }
+
+        let _ = window.emit(
+            "prerequisite-complete",
+            &serde_json::json!({ "name": "WebView2" }),
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+mod windows {
+    use winreg::enums::*;
+    use winreg::RegKey;
+
+    // Official Microsoft Evergreen bootstrapper, documented at
+    // https://developer.microsoft.com/microsoft-edge/webview2/ as the small
+    // installer that downloads the runtime.
+    const BOOTSTRAPPER_URL: &str = "https://go.microsoft.com/fwlink/p/?LinkId=2124703";
+
+    // The stable WebView2 Runtime client GUID Microsoft documents for
+    // detecting an existing install via the Clients registry key.
+    const RUNTIME_CLIENT_GUID: &str = "{F3017226-FE2A-4295-8BDF-00C3A9A7E4C5}";
+
+    /// Checks the registry locations Microsoft documents for detecting an
+    /// existing WebView2 Runtime install.
+    pub fn is_installed() -> bool {
+        let registry_roots = [
+            (
+                HKEY_LOCAL_MACHINE,
+                format!(
+                    "SOFTWARE\\WOW6432Node\\Microsoft\\EdgeUpdate\\Clients\\{}",
+                    RUNTIME_CLIENT_GUID
+                ),
+            ),
+            (
+                HKEY_LOCAL_MACHINE,
+                format!(
+                    "SOFTWARE\\Microsoft\\EdgeUpdate\\Clients\\{}",
+                    RUNTIME_CLIENT_GUID
+                ),
+            ),
+            (
+                HKEY_CURRENT_USER,
+                format!(
+                    "SOFTWARE\\Microsoft\\EdgeUpdate\\Clients\\{}",
+                    RUNTIME_CLIENT_GUID
+                ),
+            ),
+        ];
+
+        for (hkey, path) in registry_roots {
+            let root = RegKey::predef(hkey);
+            if let Ok(key) = root.open_subkey(&path) {
+                if key.get_value::<String, _>("pv").is_ok() {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Downloads and silently runs the Evergreen bootstrapper, which fetches
+    /// whatever the current WebView2 Runtime build is.
+    pub async fn install_evergreen_bootstrapper() -> Result<(), String> {
+        use tokio::io::AsyncWriteExt;
+        use tokio::process::Command;
+
+        let bootstrapper_path = std::env::temp_dir().join("MicrosoftEdgeWebview2Setup.exe");
+
+        let bytes = reqwest::get(BOOTSTRAPPER_URL)
+            .await
+            .map_err(|e| format!("Failed to download WebView2 bootstrapper: {}", e))?
+            .bytes()
+            .await
+            .map_err(|e| format!("Failed to read WebView2 bootstrapper: {}", e))?;
+
+        let mut file = tokio::fs::File::create(&bootstrapper_path)
+            .await
+            .map_err(|e| format!("Failed to save WebView2 bootstrapper: {}", e))?;
+        file.write_all(&bytes)
+            .await
+            .map_err(|e| format!("Failed to save WebView2 bootstrapper: {}", e))?;
+        drop(file);
+
+        let status = Command::new(&bootstrapper_path)
+            .args(&["/silent", "/install"])
+            .status()
+            .await
+            .map_err(|e| format!("Failed to run WebView2 bootstrapper: {}", e))?;
+
+        let _ = tokio::fs::remove_file(&bootstrapper_path).await;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(format!(
+                "WebView2 bootstrapper exited with code: {:?}",
+                status.code()
+            ))
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use serde::Serialize;
+
+/// What the frontend should do about an available update, given the
+/// currently installed version.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InstallDecision {
+    UpToDate,
+    Update,
+    Downgrade,
+    FreshInstall,
+}
+
+/// Compares the installed version (if any) against the version advertised
+/// by the update manifest, mirroring the `found_version <= app.version`
+/// guard mature updaters use to avoid reinstalling the same or an older
+/// build.
+pub fn decide_install(installed: Option<&str>, available: &str) -> Result<InstallDecision, String> {
+    let Some(installed) = installed else {
+        return Ok(InstallDecision::FreshInstall);
+    };
+
+    let installed_version = semver::Version::parse(&normalize_version(installed))
+        .map_err(|e| format!("Failed to parse installed version '{}': {}", installed, e))?;
+    let available_version = semver::Version::parse(&normalize_version(available))
+        .map_err(|e| format!("Failed to parse available version '{}': {}", available, e))?;
+
+    Ok(if available_version == installed_version {
+        InstallDecision::UpToDate
+    } else if available_version < installed_version {
+        InstallDecision::Downgrade
+    } else {
+        InstallDecision::Update
+    })
+}
+
+/// Coerces version strings from installers that don't emit strict
+/// `major.minor.patch` semver (NSIS/Inno/MSI `DisplayVersion` values are
+/// commonly two-part like `"1.2"` or four-part like `"1.2.3.4"`) into a form
+/// `semver::Version::parse` accepts: extra trailing components are dropped
+/// and missing ones are zero-padded.
+fn normalize_version(raw: &str) -> String {
+    let trimmed = raw.trim().trim_start_matches('v');
+    let mut parts: Vec<&str> = trimmed.split('.').collect();
+    parts.truncate(3);
+    while parts.len() < 3 {
+        parts.push("0");
+    }
+    parts.join(".")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fresh_install_when_nothing_is_installed() {
+        assert!(matches!(
+            decide_install(None, "1.0.0").unwrap(),
+            InstallDecision::FreshInstall
+        ));
+    }
+
+    #[test]
+    fn up_to_date_when_versions_match() {
+        assert!(matches!(
+            decide_install(Some("1.2.3"), "1.2.3").unwrap(),
+            InstallDecision::UpToDate
+        ));
+    }
+
+    #[test]
+    fn update_when_available_is_newer() {
+        assert!(matches!(
+            decide_install(Some("1.2.3"), "1.3.0").unwrap(),
+            InstallDecision::Update
+        ));
+    }
+
+    #[test]
+    fn downgrade_when_available_is_older() {
+        assert!(matches!(
+            decide_install(Some("1.3.0"), "1.2.3").unwrap(),
+            InstallDecision::Downgrade
+        ));
+    }
+
+    #[test]
+    fn tolerates_two_part_display_version() {
+        assert!(matches!(
+            decide_install(Some("1.2"), "1.2.0").unwrap(),
+            InstallDecision::UpToDate
+        ));
+    }
+
+    #[test]
+    fn tolerates_four_part_display_version() {
+        assert!(matches!(
+            decide_install(Some("1.2.3.4"), "1.2.3").unwrap(),
+            InstallDecision::UpToDate
+        ));
+    }
+}